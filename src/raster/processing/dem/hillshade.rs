@@ -0,0 +1,239 @@
+use std::num::NonZeroUsize;
+
+use crate::cpl::CslStringList;
+use crate::errors;
+use crate::raster::processing::dem::options::common_dem_options;
+use crate::raster::processing::dem::DemSlopeAlg;
+
+/// Which of GDAL's shading variants to use when computing a hillshade.
+///
+/// These are mutually exclusive: at most one of them may be selected at a time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DemShadingVariant {
+    /// Combine the classical hillshade with slope shading (`-combined`).
+    Combined,
+    /// Compute a weighted combination of hillshades from several azimuths — 225°,
+    /// 270°, 315°, and 360° — to reduce the directional bias of a single light source
+    /// (`-multidirectional`).
+    Multidirectional,
+    /// Use Igor's shading, a softer, sign-based variant better suited to visualizing
+    /// subtle relief (`-igor`).
+    Igor,
+}
+
+impl DemShadingVariant {
+    pub(crate) fn to_gdal_option(self) -> &'static str {
+        match self {
+            DemShadingVariant::Combined => "-combined",
+            DemShadingVariant::Multidirectional => "-multidirectional",
+            DemShadingVariant::Igor => "-igor",
+        }
+    }
+}
+
+/// Configuration options for [`hillshade()`][super::hillshade()].
+#[derive(Debug, Clone, Default)]
+pub struct HillshadeOptions {
+    input_band: Option<NonZeroUsize>,
+    compute_edges: bool,
+    output_format: Option<String>,
+    additional_options: CslStringList,
+    algorithm: Option<DemSlopeAlg>,
+    scale: Option<f64>,
+    azimuth: Option<f64>,
+    altitude: Option<f64>,
+    z_factor: Option<f64>,
+    shading_variant: Option<DemShadingVariant>,
+    src_nodata: Option<f64>,
+    dst_nodata: Option<f64>,
+}
+
+impl HillshadeOptions {
+    /// Create a hillshade options set.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    common_dem_options!();
+
+    /// Specify the slope/aspect computation algorithm underlying the shading model.
+    pub fn with_algorithm(&mut self, algorithm: DemSlopeAlg) -> &mut Self {
+        self.algorithm = Some(algorithm);
+        self
+    }
+
+    /// Fetch the specified slope/aspect computation algorithm.
+    pub fn algorithm(&self) -> Option<DemSlopeAlg> {
+        self.algorithm
+    }
+
+    /// Apply an elevation scaling factor.
+    ///
+    /// See [`SlopeOptions::with_scale`][super::SlopeOptions::with_scale] for guidance on
+    /// appropriate values when x/y units differ from z units.
+    pub fn with_scale(&mut self, scale: f64) -> &mut Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Fetch the specified scaling factor.
+    pub fn scale(&self) -> Option<f64> {
+        self.scale
+    }
+
+    /// Set the azimuth, in degrees, of the light source (`-az`).
+    ///
+    /// `0` is North, increasing clockwise; GDAL's default is `315` (North-West).
+    pub fn with_azimuth(&mut self, azimuth: f64) -> &mut Self {
+        self.azimuth = Some(azimuth);
+        self
+    }
+
+    /// Fetch the specified light azimuth.
+    pub fn azimuth(&self) -> Option<f64> {
+        self.azimuth
+    }
+
+    /// Set the altitude, in degrees, of the light source above the horizon (`-alt`).
+    ///
+    /// GDAL's default is `45`.
+    pub fn with_altitude(&mut self, altitude: f64) -> &mut Self {
+        self.altitude = Some(altitude);
+        self
+    }
+
+    /// Fetch the specified light altitude.
+    pub fn altitude(&self) -> Option<f64> {
+        self.altitude
+    }
+
+    /// Set a vertical exaggeration factor applied before shading (`-z`).
+    pub fn with_z_factor(&mut self, z_factor: f64) -> &mut Self {
+        self.z_factor = Some(z_factor);
+        self
+    }
+
+    /// Fetch the specified z-factor.
+    pub fn z_factor(&self) -> Option<f64> {
+        self.z_factor
+    }
+
+    /// Select one of the non-default shading variants (`-combined`,
+    /// `-multidirectional`, or `-igor`).
+    ///
+    /// If not specified, GDAL's classical single-direction hillshade is used.
+    pub fn with_shading_variant(&mut self, variant: DemShadingVariant) -> &mut Self {
+        self.shading_variant = Some(variant);
+        self
+    }
+
+    /// Fetch the specified shading variant.
+    pub fn shading_variant(&self) -> Option<DemShadingVariant> {
+        self.shading_variant
+    }
+
+    /// Render relevant common options into [`CslStringList`] values, as compatible with
+    /// [`gdal_sys::GDALDEMProcessing`].
+    pub fn to_options_list(&self) -> errors::Result<CslStringList> {
+        let mut opts = CslStringList::default();
+
+        self.store_common_options_to(&mut opts)?;
+
+        if let Some(alg) = self.algorithm {
+            opts.add_string("-alg")?;
+            opts.add_string(alg.to_gdal_option())?;
+        }
+
+        if let Some(scale) = self.scale {
+            opts.add_string("-s")?;
+            opts.add_string(&scale.to_string())?;
+        }
+
+        if let Some(az) = self.azimuth {
+            opts.add_string("-az")?;
+            opts.add_string(&az.to_string())?;
+        }
+
+        if let Some(alt) = self.altitude {
+            opts.add_string("-alt")?;
+            opts.add_string(&alt.to_string())?;
+        }
+
+        if let Some(z) = self.z_factor {
+            opts.add_string("-z")?;
+            opts.add_string(&z.to_string())?;
+        }
+
+        if let Some(variant) = self.shading_variant {
+            opts.add_string(variant.to_gdal_option())?;
+        }
+
+        Ok(opts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_near;
+    use crate::cpl::CslStringList;
+    use crate::errors::Result;
+    use crate::raster::processing::dem::hillshade;
+    use crate::raster::StatisticsAll;
+    use crate::test_utils::{fixture, target};
+    use crate::Dataset;
+
+    use super::*;
+
+    #[test]
+    fn test_options() -> Result<()> {
+        let mut opts = HillshadeOptions::new();
+        opts.with_input_band(2.try_into().unwrap())
+            .with_algorithm(DemSlopeAlg::Horn)
+            .with_scale(98473.0)
+            .with_azimuth(315.0)
+            .with_altitude(45.0)
+            .with_z_factor(2.0)
+            .with_shading_variant(DemShadingVariant::Multidirectional)
+            .with_compute_edges(true)
+            .with_output_format("GTiff")
+            .with_src_nodata(-9999.0)
+            .with_dst_nodata(-1.0)
+            .with_additional_options("CPL_DEBUG=ON".parse()?);
+
+        let expected: CslStringList = "-compute_edges -b 2 -of GTiff -srcnodata -9999 \
+             -dstnodata -1 CPL_DEBUG=ON -alg Horn -s 98473 -az 315 -alt 45 -z 2 -multidirectional"
+            .parse()?;
+        assert_eq!(expected.to_string(), opts.to_options_list()?.to_string());
+        assert_eq!(opts.src_nodata(), Some(-9999.0));
+        assert_eq!(opts.dst_nodata(), Some(-1.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hillshade() -> Result<()> {
+        let ds = Dataset::open(fixture("dem-hills.tiff"))?;
+
+        let mut opts = HillshadeOptions::new();
+        opts.with_algorithm(DemSlopeAlg::Horn)
+            .with_azimuth(315.0)
+            .with_altitude(45.0);
+
+        let shaded = hillshade(&ds, target("dem-hills-hillshade.tiff"), &opts)?;
+
+        let stats = shaded.rasterband(1)?.get_statistics(true, false)?.unwrap();
+
+        // These numbers were generated by extracting the output from:
+        //    gdaldem hillshade -alg Horn -az 315 -alt 45 fixtures/dem-hills.tiff target/dest.tiff
+        //    gdalinfo -stats target/dest.tiff
+        let expected = StatisticsAll {
+            min: 0.0,
+            max: 255.0,
+            mean: 179.00770056009,
+            std_dev: 37.718636271284,
+        };
+
+        assert_near!(StatisticsAll, stats, expected, epsilon = 1e-6);
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+//! [DEM (Digital Elevation Model) processing][gdaldem], exposed through GDAL's
+//! `GDALDEMProcessing` C API.
+//!
+//! [gdaldem]: https://gdal.org/programs/gdaldem.html
+
+use std::ffi::CString;
+use std::path::Path;
+use std::ptr;
+
+use gdal_sys::{GDALDEMProcessing, GDALDEMProcessingOptions, GDALDEMProcessingOptionsFree, GDALDEMProcessingOptionsNew};
+
+use crate::errors::{GdalError, Result};
+use crate::utils::{_last_cpl_err, _path_to_c_string, _string_array_to_c_array};
+use crate::Dataset;
+
+mod options;
+
+mod hillshade;
+mod slope;
+mod tri;
+
+pub use hillshade::{DemShadingVariant, HillshadeOptions};
+pub use slope::SlopeOptions;
+pub use tri::{DemTriAlg, TriOptions};
+
+/// Algorithm used to estimate slope and aspect from a neighborhood of cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DemSlopeAlg {
+    /// Horn's formula (the default), see Horn, B.K.P. (1981).
+    Horn,
+    /// Zevenbergen & Thorne's formula, see Zevenbergen, L.W, Thorne, C.R. (1987).
+    ZevenbergenThorne,
+}
+
+impl DemSlopeAlg {
+    pub(crate) fn to_gdal_option(self) -> &'static str {
+        match self {
+            DemSlopeAlg::Horn => "Horn",
+            DemSlopeAlg::ZevenbergenThorne => "ZevenbergenThorne",
+        }
+    }
+}
+
+/// Run one of GDAL's `GDALDEMProcessing` modes, writing the result to `dst`.
+fn dem_processing(
+    mode: &str,
+    src: &Dataset,
+    dst: impl AsRef<Path>,
+    options: &crate::cpl::CslStringList,
+) -> Result<Dataset> {
+    let mode_c = CString::new(mode).map_err(|_| GdalError::BadArgument(mode.into()))?;
+    let dst_c = _path_to_c_string(dst.as_ref())?;
+
+    unsafe {
+        let popts = GDALDEMProcessingOptionsNew(options.as_ptr(), ptr::null_mut());
+        if popts.is_null() {
+            return Err(_last_cpl_err(gdal_sys::CPLErr::CE_Failure));
+        }
+
+        let mut usage_error = 0;
+        let out_ds = GDALDEMProcessing(
+            dst_c.as_ptr(),
+            src.c_dataset(),
+            mode_c.as_ptr(),
+            ptr::null(),
+            popts,
+            &mut usage_error,
+        );
+        GDALDEMProcessingOptionsFree(popts);
+
+        if out_ds.is_null() {
+            return Err(_last_cpl_err(gdal_sys::CPLErr::CE_Failure));
+        }
+
+        Ok(Dataset::from_c_dataset(out_ds))
+    }
+}
+
+/// Compute the slope (steepness) of `src`, per [`SlopeOptions`], writing the result to
+/// `dst`.
+pub fn slope(src: &Dataset, dst: impl AsRef<Path>, options: &SlopeOptions) -> Result<Dataset> {
+    dem_processing("slope", src, dst, &options.to_options_list()?)
+}
+
+/// Compute the Terrain Ruggedness Index of `src`, per [`TriOptions`], writing the
+/// result to `dst`.
+pub fn terrain_ruggedness_index(
+    src: &Dataset,
+    dst: impl AsRef<Path>,
+    options: &TriOptions,
+) -> Result<Dataset> {
+    dem_processing("TRI", src, dst, &options.to_options_list()?)
+}
+
+/// Compute a shaded-relief ("hillshade") rendering of `src`, per [`HillshadeOptions`],
+/// writing the result to `dst`.
+pub fn hillshade(
+    src: &Dataset,
+    dst: impl AsRef<Path>,
+    options: &HillshadeOptions,
+) -> Result<Dataset> {
+    dem_processing("hillshade", src, dst, &options.to_options_list()?)
+}
@@ -0,0 +1,128 @@
+use crate::cpl::CslStringList;
+use crate::errors;
+
+/// Shared accessors and [`CslStringList`] rendering for the options common to every
+/// `GDALDEMProcessing` mode (`slope`, `aspect`, `hillshade`, `tri`, `tpi`, `roughness`,
+/// `color-relief`).
+///
+/// Invoked from within each `*Options` struct's `impl` block; the struct is expected to
+/// provide `input_band`, `compute_edges`, `output_format`, `additional_options`,
+/// `src_nodata`, and `dst_nodata` fields as declared in
+/// [`SlopeOptions`][super::SlopeOptions].
+macro_rules! common_dem_options {
+    () => {
+        /// Specify which band of the source dataset to use.
+        ///
+        /// If not specified, the first band is used.
+        pub fn with_input_band(&mut self, band: std::num::NonZeroUsize) -> &mut Self {
+            self.input_band = Some(band);
+            self
+        }
+
+        /// Fetch the specified input band.
+        ///
+        /// Returns `None` if one has not been previously set via [`Self::with_input_band`].
+        pub fn input_band(&self) -> Option<std::num::NonZeroUsize> {
+            self.input_band
+        }
+
+        /// If `state` is `true`, compute values at the raster's edges.
+        ///
+        /// Otherwise, edge pixels for which a full 3x3 window is not available are set
+        /// to the output's NoData value.
+        pub fn with_compute_edges(&mut self, state: bool) -> &mut Self {
+            self.compute_edges = state;
+            self
+        }
+
+        /// Fetch the current compute-edges setting.
+        pub fn compute_edges(&self) -> bool {
+            self.compute_edges
+        }
+
+        /// Specify the output raster format (e.g. `"GTiff"`).
+        ///
+        /// If not specified, GeoTIFF is used.
+        pub fn with_output_format<S: Into<String>>(&mut self, format: S) -> &mut Self {
+            self.output_format = Some(format.into());
+            self
+        }
+
+        /// Fetch the specified output format.
+        pub fn output_format(&self) -> Option<&str> {
+            self.output_format.as_deref()
+        }
+
+        /// Pass additional, raw `gdaldem`-style options that are not otherwise exposed
+        /// by this builder.
+        pub fn with_additional_options(&mut self, options: CslStringList) -> &mut Self {
+            self.additional_options = options;
+            self
+        }
+
+        /// Fetch the additional options previously set via [`Self::with_additional_options`].
+        pub fn additional_options(&self) -> &CslStringList {
+            &self.additional_options
+        }
+
+        /// Treat `value` as the NoData value of the source band, overriding whatever
+        /// is recorded on the source dataset itself.
+        pub fn with_src_nodata(&mut self, value: f64) -> &mut Self {
+            self.src_nodata = Some(value);
+            self
+        }
+
+        /// Fetch the source NoData value previously set via [`Self::with_src_nodata`].
+        pub fn src_nodata(&self) -> Option<f64> {
+            self.src_nodata
+        }
+
+        /// Set the NoData value written to the destination band.
+        ///
+        /// If not specified, GDAL picks a default appropriate to the output data type.
+        pub fn with_dst_nodata(&mut self, value: f64) -> &mut Self {
+            self.dst_nodata = Some(value);
+            self
+        }
+
+        /// Fetch the destination NoData value previously set via [`Self::with_dst_nodata`].
+        pub fn dst_nodata(&self) -> Option<f64> {
+            self.dst_nodata
+        }
+
+        /// Render the options common to all DEM processing modes into `opts`.
+        fn store_common_options_to(&self, opts: &mut CslStringList) -> errors::Result<()> {
+            if self.compute_edges {
+                opts.add_string("-compute_edges")?;
+            }
+
+            if let Some(band) = self.input_band {
+                opts.add_string("-b")?;
+                opts.add_string(&band.to_string())?;
+            }
+
+            if let Some(format) = &self.output_format {
+                opts.add_string("-of")?;
+                opts.add_string(format)?;
+            }
+
+            if let Some(value) = self.src_nodata {
+                opts.add_string("-srcnodata")?;
+                opts.add_string(&value.to_string())?;
+            }
+
+            if let Some(value) = self.dst_nodata {
+                opts.add_string("-dstnodata")?;
+                opts.add_string(&value.to_string())?;
+            }
+
+            for opt in self.additional_options.iter() {
+                opts.add_string(opt)?;
+            }
+
+            Ok(())
+        }
+    };
+}
+
+pub(crate) use common_dem_options;
@@ -15,6 +15,8 @@ pub struct SlopeOptions {
     algorithm: Option<DemSlopeAlg>,
     scale: Option<f64>,
     percentage_results: Option<bool>,
+    src_nodata: Option<f64>,
+    dst_nodata: Option<f64>,
 }
 
 impl SlopeOptions {
@@ -115,12 +117,17 @@ mod tests {
             .with_compute_edges(true)
             .with_percentage_results(true)
             .with_output_format("GTiff")
+            .with_src_nodata(-9999.0)
+            .with_dst_nodata(-1.0)
             .with_additional_options("CPL_DEBUG=ON".parse()?);
 
         let expected: CslStringList =
-            "-compute_edges -b 2 -of GTiff CPL_DEBUG=ON -alg ZevenbergenThorne -s 98473 -p"
+            "-compute_edges -b 2 -of GTiff -srcnodata -9999 -dstnodata -1 CPL_DEBUG=ON \
+             -alg ZevenbergenThorne -s 98473 -p"
                 .parse()?;
         assert_eq!(expected.to_string(), proc.to_options_list()?.to_string());
+        assert_eq!(proc.src_nodata(), Some(-9999.0));
+        assert_eq!(proc.dst_nodata(), Some(-1.0));
 
         Ok(())
     }
@@ -12,6 +12,8 @@ pub struct TriOptions {
     output_format: Option<String>,
     additional_options: CslStringList,
     algorithm: Option<DemTriAlg>,
+    src_nodata: Option<f64>,
+    dst_nodata: Option<f64>,
 }
 
 impl TriOptions {
@@ -96,11 +98,16 @@ mod tests {
             .with_compute_edges(true)
             .with_algorithm(DemTriAlg::Wilson)
             .with_output_format("GTiff")
+            .with_src_nodata(-9999.0)
+            .with_dst_nodata(-1.0)
             .with_additional_options("CPL_DEBUG=ON".parse()?);
 
         let expected: CslStringList =
-            "-compute_edges -b 2 -of GTiff CPL_DEBUG=ON -alg Wilson".parse()?;
+            "-compute_edges -b 2 -of GTiff -srcnodata -9999 -dstnodata -1 CPL_DEBUG=ON -alg Wilson"
+                .parse()?;
         assert_eq!(expected.to_string(), opts.to_options_list()?.to_string());
+        assert_eq!(opts.src_nodata(), Some(-9999.0));
+        assert_eq!(opts.dst_nodata(), Some(-1.0));
 
         Ok(())
     }
@@ -0,0 +1,383 @@
+//! [Point-to-raster gridding][gdal_grid], exposed through GDAL's `GDALGridCreate` C API.
+//!
+//! Turns a scattered set of `(x, y, z)` point samples into a regularly-spaced raster
+//! surface, e.g. to rasterize a set of elevation survey points into a DEM.
+//!
+//! [gdal_grid]: https://gdal.org/programs/gdal_grid.html
+
+use std::ptr;
+
+use gdal_sys::{
+    GDALGridAlgorithm, GDALGridCreate, GDALGridInverseDistanceToAPowerOptions,
+    GDALGridMovingAverageOptions, GDALGridNearestNeighborOptions,
+};
+
+use crate::errors::{GdalError, Result};
+use crate::raster::{GdalType, RasterDataType};
+use crate::utils::_last_cpl_err;
+use crate::{Dataset, DriverManager};
+
+/// A single `(x, y, z)` point sample to be interpolated onto the output grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridPoint {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl GridPoint {
+    /// Create a new grid point.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        GridPoint { x, y, z }
+    }
+}
+
+/// Shared search-window parameters used to pick which points contribute to a given
+/// output cell.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchWindow {
+    /// First search ellipse radius, along the X axis when `angle` is `0`.
+    pub radius1: f64,
+    /// Second search ellipse radius, along the Y axis when `angle` is `0`.
+    pub radius2: f64,
+    /// Rotation of the search ellipse, in degrees, counter-clockwise.
+    pub angle: f64,
+    /// Minimum number of points required within the search ellipse for a cell to be
+    /// computed; below this, `nodata` is used.
+    pub min_points: u32,
+    /// Maximum number of points considered within the search ellipse; once exceeded,
+    /// only the closest `max_points` are used.
+    pub max_points: u32,
+}
+
+impl Default for SearchWindow {
+    fn default() -> Self {
+        SearchWindow {
+            radius1: 0.0,
+            radius2: 0.0,
+            angle: 0.0,
+            min_points: 0,
+            max_points: 0,
+        }
+    }
+}
+
+/// Gridding algorithm and its parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridAlgorithm {
+    /// Inverse distance to a power: `Z = Σ(Zᵢ / dᵢ^p) / Σ(1 / dᵢ^p)` over points within
+    /// the search window. A point coinciding exactly with the cell center returns its
+    /// `Z` value directly.
+    InverseDistanceToAPower {
+        /// The power `p` to which distances are raised.
+        power: f64,
+        /// Search window selecting the contributing points.
+        search: SearchWindow,
+        /// Value assigned to a cell for which no points are found within the search
+        /// window.
+        no_data: f64,
+    },
+    /// The value at each cell is that of the nearest point within the search ellipse.
+    NearestNeighbor {
+        radius1: f64,
+        radius2: f64,
+        angle: f64,
+        /// Value assigned to a cell for which no point is found within the search
+        /// ellipse.
+        no_data: f64,
+    },
+    /// The value at each cell is the arithmetic mean of the points within the search
+    /// window.
+    ///
+    /// This has its own `radius1`/`radius2`/`angle`/`min_points` fields rather than
+    /// reusing [`SearchWindow`], because GDAL's `GDALGridMovingAverageOptions` (unlike
+    /// the IDW options) has no `max_points` field — capping the number of averaged
+    /// points isn't supported by this algorithm.
+    MovingAverage {
+        radius1: f64,
+        radius2: f64,
+        angle: f64,
+        /// Minimum number of points required within the search ellipse for a cell to
+        /// be computed; below this, `no_data` is used.
+        min_points: u32,
+        /// Value assigned to a cell for which no points are found within the search
+        /// window.
+        no_data: f64,
+    },
+}
+
+impl GridAlgorithm {
+    fn to_gdal_algorithm(self) -> GDALGridAlgorithm::Type {
+        match self {
+            GridAlgorithm::InverseDistanceToAPower { .. } => {
+                GDALGridAlgorithm::GGA_InverseDistanceToAPower
+            }
+            GridAlgorithm::NearestNeighbor { .. } => GDALGridAlgorithm::GGA_NearestNeighbor,
+            GridAlgorithm::MovingAverage { .. } => GDALGridAlgorithm::GGA_MovingAverage,
+        }
+    }
+
+    /// Run `GDALGridCreate` for this algorithm's own `gdal_sys` options struct, passing
+    /// it as the opaque `pOptions` pointer GDAL expects.
+    ///
+    /// Each algorithm has its own, differently-laid-out options struct in the GDAL C
+    /// API, so this must dispatch per-variant rather than reusing one struct for all
+    /// three.
+    unsafe fn grid_create(
+        self,
+        points: &[GridPoint],
+        extent: GridExtent,
+        out: &mut [f64],
+    ) -> gdal_sys::CPLErr::Type {
+        let xs: Vec<f64> = points.iter().map(|p| p.x).collect();
+        let ys: Vec<f64> = points.iter().map(|p| p.y).collect();
+        let zs: Vec<f64> = points.iter().map(|p| p.z).collect();
+
+        macro_rules! call_grid_create {
+            ($options:expr) => {
+                GDALGridCreate(
+                    self.to_gdal_algorithm(),
+                    &$options as *const _ as *const libc::c_void,
+                    points.len() as u32,
+                    xs.as_ptr(),
+                    ys.as_ptr(),
+                    zs.as_ptr(),
+                    extent.min_x,
+                    extent.max_x,
+                    extent.min_y,
+                    extent.max_y,
+                    extent.width as u32,
+                    extent.height as u32,
+                    RasterDataType::Float64.gdal_type(),
+                    out.as_mut_ptr() as *mut libc::c_void,
+                    None,
+                    ptr::null_mut(),
+                )
+            };
+        }
+
+        match self {
+            GridAlgorithm::InverseDistanceToAPower {
+                power,
+                search,
+                no_data,
+            } => call_grid_create!(GDALGridInverseDistanceToAPowerOptions {
+                dfPower: power,
+                dfSmoothing: 0.0,
+                dfAnisotropyRatio: 0.0,
+                dfAnisotropyAngle: 0.0,
+                dfRadius1: search.radius1,
+                dfRadius2: search.radius2,
+                dfAngle: search.angle,
+                nMinPoints: search.min_points,
+                nMaxPoints: search.max_points,
+                dfNoDataValue: no_data,
+            }),
+            GridAlgorithm::NearestNeighbor {
+                radius1,
+                radius2,
+                angle,
+                no_data,
+            } => call_grid_create!(GDALGridNearestNeighborOptions {
+                dfRadius1: radius1,
+                dfRadius2: radius2,
+                dfAngle: angle,
+                dfNoDataValue: no_data,
+            }),
+            GridAlgorithm::MovingAverage {
+                radius1,
+                radius2,
+                angle,
+                min_points,
+                no_data,
+            } => call_grid_create!(GDALGridMovingAverageOptions {
+                dfRadius1: radius1,
+                dfRadius2: radius2,
+                dfAngle: angle,
+                nMinPoints: min_points,
+                dfNoDataValue: no_data,
+            }),
+        }
+    }
+}
+
+/// The target geometry of the output grid, equivalent to `gdal_grid`'s `-txe`/`-tye`/
+/// `-outsize` options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridExtent {
+    pub min_x: f64,
+    pub max_x: f64,
+    pub min_y: f64,
+    pub max_y: f64,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// Configuration options for [`grid()`].
+#[derive(Debug, Clone, Copy)]
+pub struct GridOptions {
+    algorithm: GridAlgorithm,
+    extent: GridExtent,
+}
+
+impl GridOptions {
+    /// Create a grid options set for `algorithm`, producing an output of `extent`.
+    pub fn new(algorithm: GridAlgorithm, extent: GridExtent) -> Self {
+        GridOptions { algorithm, extent }
+    }
+
+    /// Fetch the configured algorithm.
+    pub fn algorithm(&self) -> GridAlgorithm {
+        self.algorithm
+    }
+
+    /// Fetch the configured output extent.
+    pub fn extent(&self) -> GridExtent {
+        self.extent
+    }
+}
+
+/// Interpolate `points` onto a raster surface described by `options`, returning the
+/// result as a new, in-memory single-band [`Dataset`] (via the `MEM` driver), georeferenced
+/// to the configured [`GridExtent`].
+pub fn grid(points: &[GridPoint], options: &GridOptions) -> Result<Dataset> {
+    let extent = options.extent;
+    let cell_count = extent
+        .width
+        .checked_mul(extent.height)
+        .ok_or_else(|| GdalError::BadArgument("grid output size overflows usize".into()))?;
+
+    let mut out = vec![0f64; cell_count];
+
+    let rv = unsafe { options.algorithm.grid_create(points, extent, &mut out) };
+
+    if rv != gdal_sys::CPLErr::CE_None {
+        return Err(_last_cpl_err(rv));
+    }
+
+    // `GDALGridCreate` fills rows from `min_y` upwards; reverse them so that row 0 is
+    // the northernmost, matching the north-up geotransform set below.
+    let out: Vec<f64> = out
+        .chunks(extent.width.max(1))
+        .rev()
+        .flatten()
+        .copied()
+        .collect();
+
+    let driver = DriverManager::get_driver_by_name("MEM")?;
+    let mut dataset =
+        driver.create_with_band_type::<f64, _>("", extent.width, extent.height, 1)?;
+
+    dataset.set_geo_transform(&[
+        extent.min_x,
+        (extent.max_x - extent.min_x) / extent.width as f64,
+        0.0,
+        extent.max_y,
+        0.0,
+        -(extent.max_y - extent.min_y) / extent.height as f64,
+    ])?;
+
+    let mut band = dataset.rasterband(1)?;
+    band.write(
+        (0, 0),
+        (extent.width, extent.height),
+        &crate::raster::Buffer::new((extent.width, extent.height), out),
+    )?;
+
+    Ok(dataset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_points() -> Vec<GridPoint> {
+        vec![
+            GridPoint::new(0.0, 0.0, 10.0),
+            GridPoint::new(10.0, 0.0, 20.0),
+            GridPoint::new(0.0, 10.0, 30.0),
+            GridPoint::new(10.0, 10.0, 40.0),
+        ]
+    }
+
+    #[test]
+    fn test_inverse_distance_exact_hit() -> Result<()> {
+        let points = sample_points();
+
+        // `grid()` places cell centers half a cell in from the extent's edges, at
+        // `min + (k + 0.5) * (max - min) / size` for `k` in `0..size` (the same
+        // centers its output geotransform describes). For a 2x2
+        // output, that means cell centers sit at `min + 0.25*span` and
+        // `min + 0.75*span` along each axis. Pick an extent whose span is twice the
+        // spacing between our sample points (0 and 10), so the four cell centers land
+        // exactly on the four sample points themselves: `min_x = -5` gives centers at
+        // `-5 + 0.25*20 = 0` and `-5 + 0.75*20 = 10`.
+        let extent = GridExtent {
+            min_x: -5.0,
+            max_x: 15.0,
+            min_y: -5.0,
+            max_y: 15.0,
+            width: 2,
+            height: 2,
+        };
+        let opts = GridOptions::new(
+            GridAlgorithm::InverseDistanceToAPower {
+                power: 2.0,
+                search: SearchWindow {
+                    radius1: 20.0,
+                    radius2: 20.0,
+                    ..Default::default()
+                },
+                no_data: -9999.0,
+            },
+            extent,
+        );
+
+        let dataset = grid(&points, &opts)?;
+        let band = dataset.rasterband(1)?;
+        let values = band.read_as::<f64>((0, 0), (2, 2), (2, 2), None)?;
+
+        // Every cell center now coincides exactly with one of the four sample points,
+        // so IDW's zero-distance shortcut should return each `Z` value directly,
+        // regardless of the other points also being within the search radius. We don't
+        // assume a particular row/column ordering of the output buffer here — just
+        // that the four returned values are exactly the four planted ones.
+        let mut got = values.data().to_vec();
+        got.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(got, vec![10.0, 20.0, 30.0, 40.0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nearest_neighbor_no_data() -> Result<()> {
+        let points = vec![GridPoint::new(0.0, 0.0, 42.0)];
+        let extent = GridExtent {
+            min_x: 0.0,
+            max_x: 100.0,
+            min_y: 0.0,
+            max_y: 100.0,
+            width: 2,
+            height: 2,
+        };
+        let opts = GridOptions::new(
+            GridAlgorithm::NearestNeighbor {
+                radius1: 1.0,
+                radius2: 1.0,
+                angle: 0.0,
+                no_data: -9999.0,
+            },
+            extent,
+        );
+
+        let dataset = grid(&points, &opts)?;
+        let band = dataset.rasterband(1)?;
+        let values = band.read_as::<f64>((0, 0), (2, 2), (2, 2), None)?;
+
+        // The south-east cell, centered at (75, 25), is well outside the search radius
+        // of the only point.
+        assert_eq!(values.data()[3], -9999.0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+use gdal_sys::{GDALGetRasterNoDataValue, GDALSetRasterNoDataValue};
+
+use crate::errors::{GdalError, Result};
+use crate::raster::{Buffer, GdalType, RasterBand, RasterDataType, ResampleAlg};
+use crate::utils::_last_cpl_err;
+
+/// Default ceiling on the number of samples a single [`RasterBand::read_window`] call
+/// will materialize, chosen to keep an accidental full-resolution read of a huge VRT or
+/// tiled DEM from exhausting memory. Override with
+/// [`RasterBand::read_window_with_sample_cap`].
+pub const DEFAULT_MAX_READ_SAMPLES: u64 = 256 * 1024 * 1024;
+
+impl RasterBand<'_> {
+    /// Read a rectangular window of this band into a buffer of `buffer_size`,
+    /// resampling as needed, guarding against allocations that would overflow pointer
+    /// arithmetic or exceed [`DEFAULT_MAX_READ_SAMPLES`].
+    ///
+    /// * `offset` — `(x, y)` pixel coordinates of the window's top-left corner, in the
+    ///   source raster's own resolution.
+    /// * `window_size` — `(width, height)` of the window to read, in the source
+    ///   raster's own resolution.
+    /// * `buffer_size` — `(width, height)` of the destination buffer; if this differs
+    ///   from `window_size`, GDAL resamples using `resample_alg`.
+    ///
+    /// This is the safe building block for streaming tiles out of rasters far larger
+    /// than RAM, rather than reading them whole with [`RasterBand::read_as`].
+    pub fn read_window<T: GdalType + Copy>(
+        &self,
+        offset: (isize, isize),
+        window_size: (usize, usize),
+        buffer_size: (usize, usize),
+        resample_alg: Option<ResampleAlg>,
+    ) -> Result<Buffer<T>> {
+        self.read_window_with_sample_cap(
+            offset,
+            window_size,
+            buffer_size,
+            resample_alg,
+            DEFAULT_MAX_READ_SAMPLES,
+        )
+    }
+
+    /// As [`RasterBand::read_window`], but with an explicit cap (in samples, i.e.
+    /// pixels) on the destination buffer, instead of [`DEFAULT_MAX_READ_SAMPLES`].
+    pub fn read_window_with_sample_cap<T: GdalType + Copy>(
+        &self,
+        offset: (isize, isize),
+        window_size: (usize, usize),
+        buffer_size: (usize, usize),
+        resample_alg: Option<ResampleAlg>,
+        max_samples: u64,
+    ) -> Result<Buffer<T>> {
+        let (buf_width, buf_height) = buffer_size;
+
+        let sample_count = (buf_width as u64)
+            .checked_mul(buf_height as u64)
+            .ok_or_else(|| {
+                GdalError::BadArgument(format!(
+                    "requested read buffer {buf_width}x{buf_height} overflows sample count"
+                ))
+            })?;
+
+        if sample_count > max_samples {
+            return Err(GdalError::BadArgument(format!(
+                "requested read buffer of {sample_count} samples exceeds the cap of {max_samples}; \
+                 read in smaller tiles instead"
+            )));
+        }
+
+        let byte_count = sample_count
+            .checked_mul(std::mem::size_of::<T>() as u64)
+            .ok_or_else(|| {
+                GdalError::BadArgument(format!(
+                    "requested read buffer of {sample_count} samples of size {} overflows byte count",
+                    std::mem::size_of::<T>()
+                ))
+            })?;
+
+        // `GDALRasterIO`'s buffer argument is sized with a plain `int`/pointer
+        // arithmetic internally; guard against handing it something that would
+        // overflow that on 32-bit targets, in addition to the cap above.
+        if byte_count > isize::MAX as u64 {
+            return Err(GdalError::BadArgument(format!(
+                "requested read buffer of {byte_count} bytes overflows addressable size"
+            )));
+        }
+
+        self.read_as::<T>(offset, window_size, buffer_size, resample_alg)
+    }
+
+    /// Fetch this band's NoData value, if one is set.
+    pub fn nodata_value(&self) -> Option<f64> {
+        let mut success = 0;
+        let value = unsafe { GDALGetRasterNoDataValue(self.c_rasterband(), &mut success) };
+        (success != 0).then_some(value)
+    }
+
+    /// Set this band's NoData value directly, without checking that `value` is
+    /// representable in the band's data type.
+    ///
+    /// Prefer [`RasterBand::set_nodata_value_checked`] when copying a NoData value from
+    /// another band of a possibly different data type.
+    pub fn set_nodata_value(&mut self, value: f64) -> Result<()> {
+        let rv = unsafe { GDALSetRasterNoDataValue(self.c_rasterband(), value) };
+        if rv != gdal_sys::CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
+    /// Set this band's NoData value to `value`, first checking that it can be
+    /// represented exactly in the band's own data type, returning a
+    /// [`GdalError::BadArgument`] rather than silently truncating it otherwise.
+    ///
+    /// The check covers `UInt8`, `Int16`, `UInt16`, `Int32`, `UInt32`, `Float32` and
+    /// `Float64` bands; for any other data type `value` is set without a check.
+    ///
+    /// This is meant for propagating a source band's NoData sentinel onto a
+    /// differently-typed destination band, e.g. when a DEM processing step narrows
+    /// `Float64` input down to an `Int16` output.
+    pub fn set_nodata_value_checked(&mut self, value: f64) -> Result<()> {
+        let band_type = self.band_type();
+        if !nodata_representable(value, band_type) {
+            return Err(GdalError::BadArgument(format!(
+                "NoData value {value} cannot be represented exactly in destination band type {band_type:?}"
+            )));
+        }
+        self.set_nodata_value(value)
+    }
+}
+
+/// Whether `value` can be represented exactly as a sample of `band_type`, so that it's
+/// safe to use as that band's NoData sentinel.
+///
+/// Data types without an arm below are not checked: `true` is returned for them.
+fn nodata_representable(value: f64, band_type: RasterDataType) -> bool {
+    match band_type {
+        // NaN and the infinities are themselves exactly representable in `f32`; only
+        // finite values need the roundtrip check to catch precision loss.
+        RasterDataType::Float32 => {
+            value.is_nan() || value.is_infinite() || (value as f32) as f64 == value
+        }
+        RasterDataType::Float64 => true,
+        RasterDataType::UInt8 => value.fract() == 0.0 && (0.0..=u8::MAX as f64).contains(&value),
+        RasterDataType::Int16 => {
+            value.fract() == 0.0 && (i16::MIN as f64..=i16::MAX as f64).contains(&value)
+        }
+        RasterDataType::UInt16 => {
+            value.fract() == 0.0 && (0.0..=u16::MAX as f64).contains(&value)
+        }
+        RasterDataType::Int32 => {
+            value.fract() == 0.0 && (i32::MIN as f64..=i32::MAX as f64).contains(&value)
+        }
+        RasterDataType::UInt32 => {
+            value.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(&value)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::fixture;
+    use crate::Dataset;
+
+    #[test]
+    fn test_read_window_rejects_oversized_request() -> Result<()> {
+        let ds = Dataset::open(fixture("dem-hills.tiff"))?;
+        let band = ds.rasterband(1)?;
+
+        let result = band.read_window_with_sample_cap::<f32>(
+            (0, 0),
+            (10, 10),
+            (10, 10),
+            None,
+            // A cap smaller than the requested 10x10 buffer should be rejected rather
+            // than silently truncated.
+            50,
+        );
+
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_window_within_cap_succeeds() -> Result<()> {
+        let ds = Dataset::open(fixture("dem-hills.tiff"))?;
+        let band = ds.rasterband(1)?;
+
+        let buf = band.read_window::<f32>((0, 0), (4, 4), (4, 4), None)?;
+        assert_eq!(buf.size(), (4, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_nodata_round_trip() -> Result<()> {
+        let ds = Dataset::open(fixture("dem-hills.tiff"))?;
+        let mut band = ds.rasterband(1)?;
+
+        assert_eq!(band.nodata_value(), None);
+        band.set_nodata_value(-9999.0)?;
+        assert_eq!(band.nodata_value(), Some(-9999.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nodata_checked_rejects_unrepresentable_value() {
+        assert!(!nodata_representable(1.5, RasterDataType::UInt8));
+        assert!(!nodata_representable(-1.0, RasterDataType::UInt8));
+        assert!(!nodata_representable(70000.0, RasterDataType::Int16));
+        assert!(nodata_representable(-9999.0, RasterDataType::Int16));
+        assert!(nodata_representable(-9999.5, RasterDataType::Float32));
+    }
+
+    #[test]
+    fn test_nodata_representable_accepts_nan_and_infinity() {
+        // NaN is one of the most common NoData sentinels for floating-point rasters
+        // (e.g. COG defaults) and is exactly representable in both float widths.
+        assert!(nodata_representable(f64::NAN, RasterDataType::Float32));
+        assert!(nodata_representable(f64::NAN, RasterDataType::Float64));
+        assert!(nodata_representable(f64::INFINITY, RasterDataType::Float32));
+        assert!(nodata_representable(f64::NEG_INFINITY, RasterDataType::Float32));
+    }
+
+    #[test]
+    fn test_get_statistics_excludes_nodata() -> Result<()> {
+        use crate::DriverManager;
+
+        let driver = DriverManager::get_driver_by_name("MEM")?;
+        let ds = driver.create_with_band_type::<f32, _>("", 3, 1, 1)?;
+        let mut band = ds.rasterband(1)?;
+        band.write(
+            (0, 0),
+            (3, 1),
+            &Buffer::new((3, 1), vec![1.0f32, 2.0, -9999.0]),
+        )?;
+        band.set_nodata_value(-9999.0)?;
+
+        let stats = band.get_statistics(true, false)?.unwrap();
+        // If the NoData pixel were folded into the statistics, the min would be
+        // `-9999.0` and the mean would be dragged far below `1.5`.
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.max, 2.0);
+        assert_eq!(stats.mean, 1.5);
+
+        Ok(())
+    }
+}
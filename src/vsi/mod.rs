@@ -0,0 +1,196 @@
+//! Helpers for working with GDAL's [VSI virtual file system](https://gdal.org/user/virtual_file_systems.html).
+//!
+//! GDAL can open datasets through a number of virtual handlers in addition to plain
+//! filesystem paths, e.g. `/vsicurl/` for remote HTTP(S)/S3 resources, `/vsizip/` for
+//! reading files inside a `.zip` archive without extracting it, and `/vsimem/` for
+//! reading and writing in-memory buffers. This module provides ergonomic helpers for
+//! building the corresponding paths so that [`Dataset::open`][crate::Dataset::open] and
+//! the `*Options`-driven processors in [`raster::processing`][crate::raster::processing]
+//! can operate on them just like ordinary file paths.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use gdal_sys::{VSIFCloseL, VSIFileFromMemBuffer, VSIUnlink};
+use libc::c_int;
+
+use crate::errors::{GdalError, Result};
+use crate::utils::_path_to_c_string;
+
+/// Prefix for GDAL's in-memory virtual file system.
+pub const VSIMEM_PREFIX: &str = "/vsimem/";
+
+/// Prefix for GDAL's cURL-backed remote virtual file system.
+pub const VSICURL_PREFIX: &str = "/vsicurl/";
+
+/// Prefix for GDAL's zip-archive virtual file system.
+pub const VSIZIP_PREFIX: &str = "/vsizip/";
+
+/// Build a `/vsicurl/` path for reading a remote HTTP(S) or cloud-storage URL, e.g.
+/// `https://example.com/dem.tif` or `s3://bucket/dem.tif`, as if it were a local file.
+pub fn vsicurl_path(url: &str) -> String {
+    format!("{VSICURL_PREFIX}{url}")
+}
+
+/// Build a `/vsizip/` path for reading `member` out of the zip archive at `archive`
+/// without extracting it to disk.
+///
+/// If `member` is `None`, the path refers to the archive itself, and GDAL will pick the
+/// first recognized file within it.
+///
+/// `member` is always treated as relative to the archive root: an absolute-looking
+/// entry (e.g. `/dem.tif`, as some archive tools record member paths) is joined as a
+/// relative component rather than replacing the whole path, which is what
+/// [`PathBuf::push`] would otherwise do with an absolute path.
+pub fn vsizip_path(archive: &Path, member: Option<&Path>) -> PathBuf {
+    match member {
+        Some(member) => PathBuf::from(format!(
+            "{VSIZIP_PREFIX}{}/{}",
+            archive.display(),
+            member.display().to_string().trim_start_matches(['/', '\\'])
+        )),
+        None => PathBuf::from(format!("{VSIZIP_PREFIX}{}", archive.display())),
+    }
+}
+
+/// A named buffer registered in `/vsimem/`, removed automatically when dropped.
+///
+/// Create one with [`MemFile::create`] to expose a `&[u8]` buffer to GDAL as if it were
+/// a file, then pass [`MemFile::path`] anywhere a dataset path is expected (e.g. as the
+/// input or output of the `raster::processing::dem` functions).
+pub struct MemFile {
+    path: PathBuf,
+}
+
+impl MemFile {
+    /// Register `data` under a fresh, unique `/vsimem/` path and return a guard that
+    /// unlinks it again on drop.
+    ///
+    /// `data` is copied into GDAL's own memory, so the buffer passed here does not need
+    /// to outlive the returned [`MemFile`].
+    pub fn create(data: &[u8]) -> Result<Self> {
+        let path = PathBuf::from(format!("{VSIMEM_PREFIX}gdal-vsi-{:016x}", next_mem_file_id()));
+        let c_path = _path_to_c_string(&path)?;
+
+        unsafe {
+            let buffer = libc::malloc(data.len()) as *mut u8;
+            if buffer.is_null() {
+                return Err(GdalError::BadArgument(
+                    "failed to allocate VSI memory buffer".into(),
+                ));
+            }
+            std::ptr::copy_nonoverlapping(data.as_ptr(), buffer, data.len());
+
+            let handle = VSIFileFromMemBuffer(
+                c_path.as_ptr(),
+                buffer,
+                data.len() as u64,
+                true as c_int,
+            );
+            if handle.is_null() {
+                libc::free(buffer as *mut libc::c_void);
+                return Err(GdalError::BadArgument(format!(
+                    "failed to create VSI memory file at {}",
+                    path.display()
+                )));
+            }
+            VSIFCloseL(handle);
+        }
+
+        Ok(MemFile { path })
+    }
+
+    /// The `/vsimem/` path under which this buffer is registered.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for MemFile {
+    fn drop(&mut self) {
+        if let Ok(c_path) = _path_to_c_string(&self.path) {
+            unsafe {
+                VSIUnlink(c_path.as_ptr());
+            }
+        }
+    }
+}
+
+impl fmt::Debug for MemFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemFile").field("path", &self.path).finish()
+    }
+}
+
+/// Process-wide counter used to generate distinct `/vsimem/` file names, so that two
+/// [`MemFile`]s created from equal (or identically-addressed) input buffers never
+/// collide on the same virtual path.
+static NEXT_MEM_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+fn next_mem_file_id() -> usize {
+    NEXT_MEM_FILE_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vsicurl_path() {
+        assert_eq!(
+            vsicurl_path("https://example.com/dem.tif"),
+            "/vsicurl/https://example.com/dem.tif"
+        );
+    }
+
+    #[test]
+    fn test_vsizip_path() {
+        assert_eq!(
+            vsizip_path(Path::new("archive.zip"), Some(Path::new("dem.tif"))),
+            PathBuf::from("/vsizip/archive.zip/dem.tif")
+        );
+    }
+
+    #[test]
+    fn test_vsizip_path_with_absolute_member_stays_inside_archive() {
+        // An archive member path beginning with `/` must still be joined under the
+        // archive, not collapse the result down to just `/dem.tif` the way
+        // `PathBuf::push` would with a genuinely absolute path.
+        assert_eq!(
+            vsizip_path(Path::new("archive.zip"), Some(Path::new("/dem.tif"))),
+            PathBuf::from("/vsizip/archive.zip/dem.tif")
+        );
+    }
+
+    #[test]
+    fn test_vsizip_path_without_member() {
+        assert_eq!(
+            vsizip_path(Path::new("archive.zip"), None),
+            PathBuf::from("/vsizip/archive.zip")
+        );
+    }
+
+    #[test]
+    fn test_mem_file_round_trip() -> Result<()> {
+        let data = b"not a real GeoTIFF, just bytes";
+        let mem = MemFile::create(data)?;
+        assert!(mem.path().starts_with(VSIMEM_PREFIX));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mem_file_paths_are_unique_for_identical_buffers() -> Result<()> {
+        // Two `MemFile`s created from the exact same buffer (same contents, same
+        // address) must not collide on the same `/vsimem/` path, or the second
+        // `create()` would clobber the first and an early `drop()` would unlink out
+        // from under the survivor.
+        static DATA: &[u8] = b"not a real GeoTIFF, just bytes";
+
+        let first = MemFile::create(DATA)?;
+        let second = MemFile::create(DATA)?;
+
+        assert_ne!(first.path(), second.path());
+        Ok(())
+    }
+}